@@ -1,7 +1,11 @@
 use anyhow::{Context, Result};
 use clap::Parser;
+use rayon::prelude::*;
 use rspolib::Save;
-use rspolib::{FileOptions, POFile, pofile};
+use rspolib::SaveAsMOFile;
+use rspolib::{FileOptions, POEntry, POFile, pofile};
+use serde::Serialize;
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -21,6 +25,51 @@ struct Cli {
     /// Enable verbose output
     #[arg(short, long)]
     verbose: bool,
+
+    /// Compile merged catalogs to a binary messages.mo next to messages.po
+    #[arg(short, long)]
+    compile: bool,
+
+    /// Path (relative to base_path, or absolute) to the .pot template each
+    /// locale's messages.po is reconciled against before extraction
+    #[arg(short = 't', long, default_value = "messages.pot")]
+    template: String,
+
+    /// Ordered fallback chain for a locale, e.g. `pt_BR=pt,en`. Repeat for
+    /// multiple locales. A still-missing string is pre-filled (and flagged
+    /// `#, fuzzy`) from the first ancestor in the chain that has it.
+    #[arg(long = "fallback")]
+    fallback: Vec<String>,
+
+    /// Quarantine `#, fuzzy` entries into messages-missing.po as incomplete
+    /// (on by default)
+    #[arg(long, conflicts_with = "no_fuzzy")]
+    include_fuzzy: bool,
+
+    /// Treat `#, fuzzy` entries as already translated, opting out of fuzzy
+    /// quarantine
+    #[arg(long)]
+    no_fuzzy: bool,
+
+    /// Write a JSON coverage summary across all locales to this path
+    #[arg(long)]
+    report: Option<String>,
+}
+
+/// Parses `--fallback locale=ancestor1,ancestor2` entries into a lookup
+/// from target locale to its ordered list of ancestor locales.
+fn parse_fallback_chains(raw: &[String]) -> HashMap<String, Vec<String>> {
+    raw.iter()
+        .filter_map(|spec| {
+            let (locale, chain) = spec.split_once('=')?;
+            let chain = chain
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+            Some((locale.trim().to_string(), chain))
+        })
+        .collect()
 }
 
 fn main() -> Result<()> {
@@ -30,35 +79,104 @@ fn main() -> Result<()> {
         println!("Scanning for locales in '{}' directory...", cli.base_path);
     }
 
-    extract_missing_translations(&cli.base_path, cli.verbose)
+    let fallback_chains = parse_fallback_chains(&cli.fallback);
+    let include_fuzzy = cli.include_fuzzy || !cli.no_fuzzy;
+
+    extract_missing_translations(
+        &cli.base_path,
+        cli.verbose,
+        cli.compile,
+        &cli.template,
+        &fallback_chains,
+        include_fuzzy,
+        cli.report.as_deref(),
+    )
+}
+
+/// Outcome of processing a single locale, including its buffered verbose
+/// output so concurrent locales never interleave their log lines.
+struct LocaleOutcome {
+    locale: String,
+    result: Result<Option<LocaleCoverage>>,
+    log: Vec<String>,
 }
 
-fn extract_missing_translations(base_path: &str, verbose: bool) -> Result<()> {
+fn extract_missing_translations(
+    base_path: &str,
+    verbose: bool,
+    compile: bool,
+    template: &str,
+    fallback_chains: &HashMap<String, Vec<String>>,
+    include_fuzzy: bool,
+    report_path: Option<&str>,
+) -> Result<()> {
     let locales_dir = Path::new(base_path);
     if !locales_dir.exists() {
         anyhow::bail!("Directory '{}' does not exist", base_path);
     }
 
+    // Collect the locale list upfront so processing itself can run
+    // concurrently; each locale only ever writes its own messages.po /
+    // messages-missing.po.
+    let locales: Vec<String> = fs::read_dir(locales_dir)
+        .with_context(|| format!("Failed to read directory '{}'", base_path))?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .collect();
+
+    // Fallback lookups (chunk0-4) read *other* locales' messages.po, which
+    // would otherwise race a concurrent worker's merge-back save for that
+    // same locale. Snapshot every locale's catalog as it stood before the
+    // parallel phase starts, so ancestor lookups are consistent regardless
+    // of worker scheduling.
+    let snapshot: HashMap<String, POFile> = locales
+        .iter()
+        .filter_map(|locale| {
+            let path = PathBuf::from(base_path).join(locale).join("messages.po");
+            pofile(path.as_path()).ok().map(|po| (locale.clone(), po))
+        })
+        .collect();
+
+    let outcomes: Vec<LocaleOutcome> = locales
+        .into_par_iter()
+        .map(|locale| {
+            let mut log = Vec::new();
+            let result = process_locale(
+                base_path,
+                &locale,
+                verbose,
+                compile,
+                template,
+                fallback_chains,
+                &snapshot,
+                include_fuzzy,
+                &mut log,
+            );
+            LocaleOutcome {
+                locale,
+                result,
+                log,
+            }
+        })
+        .collect();
+
     let mut processed = 0;
     let mut errors = 0;
+    let mut coverages = Vec::new();
 
-    for entry in fs::read_dir(locales_dir)
-        .with_context(|| format!("Failed to read directory '{}'", base_path))?
-    {
-        let entry = entry?;
-        let path = entry.path();
-
-        if path.is_dir() {
-            if let Some(locale) = path.file_name().and_then(|name| name.to_str()) {
-                match process_locale(base_path, locale, verbose) {
-                    Ok(_) => {
-                        processed += 1;
-                    }
-                    Err(e) => {
-                        eprintln!("Error processing locale '{}': {}", locale, e);
-                        errors += 1;
-                    }
-                }
+    for outcome in outcomes {
+        for line in outcome.log {
+            println!("{}", line);
+        }
+        match outcome.result {
+            Ok(coverage) => {
+                processed += 1;
+                coverages.extend(coverage);
+            }
+            Err(e) => {
+                eprintln!("Error processing locale '{}': {}", outcome.locale, e);
+                errors += 1;
             }
         }
     }
@@ -70,6 +188,14 @@ fn extract_missing_translations(base_path: &str, verbose: bool) -> Result<()> {
         );
     }
 
+    if let Some(report_path) = report_path {
+        write_coverage_report(&coverages, Path::new(report_path))
+            .with_context(|| format!("Failed to write coverage report to '{}'", report_path))?;
+        if verbose {
+            println!("  📊 Coverage report written to {}", report_path);
+        }
+    }
+
     if errors > 0 {
         anyhow::bail!("Completed with {} errors", errors);
     }
@@ -77,17 +203,127 @@ fn extract_missing_translations(base_path: &str, verbose: bool) -> Result<()> {
     Ok(())
 }
 
-fn process_locale(base_path: &str, locale: &str, verbose: bool) -> Result<()> {
+/// Per-locale translation coverage, as returned by [`extract_current_missing`]
+/// and aggregated into the `--report` JSON artifact.
+#[derive(Serialize)]
+struct LocaleCoverage {
+    locale: String,
+    total: usize,
+    translated: usize,
+    missing: usize,
+    fuzzy: usize,
+    percent_coverage: f64,
+    missing_msgids: Vec<String>,
+}
+
+/// Machine-readable summary covering every locale, written by `--report`.
+#[derive(Serialize)]
+struct CoverageReport {
+    total_locales: usize,
+    total_entries: usize,
+    total_translated: usize,
+    total_missing: usize,
+    total_fuzzy: usize,
+    percent_coverage: f64,
+    locales: Vec<LocaleCoverage>,
+}
+
+/// Aggregates the per-locale coverage figures into a single JSON artifact,
+/// giving CI pipelines a stable file to gate merges on or render dashboards
+/// from, instead of scraping verbose stdout.
+fn write_coverage_report(coverages: &[LocaleCoverage], report_path: &Path) -> Result<()> {
+    let total_locales = coverages.len();
+    let total_entries: usize = coverages.iter().map(|c| c.total).sum();
+    let total_translated: usize = coverages.iter().map(|c| c.translated).sum();
+    let total_missing: usize = coverages.iter().map(|c| c.missing).sum();
+    let total_fuzzy: usize = coverages.iter().map(|c| c.fuzzy).sum();
+
+    let report = CoverageReport {
+        total_locales,
+        total_entries,
+        total_translated,
+        total_missing,
+        total_fuzzy,
+        percent_coverage: if total_entries == 0 {
+            100.0
+        } else {
+            (total_translated as f64 / total_entries as f64) * 100.0
+        },
+        locales: coverages
+            .iter()
+            .map(|c| LocaleCoverage {
+                locale: c.locale.clone(),
+                total: c.total,
+                translated: c.translated,
+                missing: c.missing,
+                fuzzy: c.fuzzy,
+                percent_coverage: c.percent_coverage,
+                missing_msgids: c.missing_msgids.clone(),
+            })
+            .collect(),
+    };
+
+    let file = fs::File::create(report_path)?;
+    serde_json::to_writer_pretty(file, &report)?;
+    Ok(())
+}
+
+/// Parses `nplurals` out of the catalog's `Plural-Forms` header line.
+///
+/// Falls back to 2 (the common "singular/plural" form) when the header is
+/// absent or doesn't carry a `Plural-Forms` entry, so catalogs without any
+/// plural metadata still get sane singular/plural handling.
+fn header_nplurals(main_po: &POFile) -> usize {
+    main_po
+        .metadata
+        .get("Plural-Forms")
+        .and_then(|value| {
+            value.split(';').find_map(|part| {
+                part.trim()
+                    .strip_prefix("nplurals=")?
+                    .trim()
+                    .parse::<usize>()
+                    .ok()
+            })
+        })
+        .unwrap_or(2)
+}
+
+/// Returns the indices (`0..nplurals`) whose `msgstr_plural[i]` is absent or
+/// whitespace-only for a plural entry.
+fn missing_plural_indices(entry: &POEntry, nplurals: usize) -> Vec<usize> {
+    (0..nplurals)
+        .filter(|i| match entry.msgstr_plural.get(*i) {
+            Some(s) => s.trim().is_empty(),
+            None => true,
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn process_locale(
+    base_path: &str,
+    locale: &str,
+    verbose: bool,
+    compile: bool,
+    template: &str,
+    fallback_chains: &HashMap<String, Vec<String>>,
+    snapshot: &HashMap<String, POFile>,
+    include_fuzzy: bool,
+    log: &mut Vec<String>,
+) -> Result<Option<LocaleCoverage>> {
     let messages_path = PathBuf::from(base_path).join(locale).join("messages.po");
     let messages_missing_path = PathBuf::from(base_path)
         .join(locale)
         .join("messages-missing.po");
+    let template_path = PathBuf::from(base_path).join(template);
 
     if !messages_path.exists() {
-        return Ok(()); // Skip if no messages.po exists
+        return Ok(None); // Skip if no messages.po exists
     }
 
     // First, check if there's a messages-missing.po with non-empty translations to merge back
+    let coverage;
     if messages_missing_path.exists() {
         if let Ok(missing_po) = pofile(messages_missing_path.as_path()) {
             let mut main_po = pofile(messages_path.as_path()).map_err(|e| {
@@ -104,6 +340,47 @@ fn process_locale(base_path: &str, locale: &str, verbose: bool) -> Result<()> {
                     continue;
                 }
 
+                if missing_entry.msgid_plural.is_some() {
+                    // Plural entry: copy back each filled msgstr_plural[i]
+                    // individually, keyed on (msgid, msgctxt, msgid_plural) so
+                    // we don't clobber forms the translator left untouched.
+                    let filled: Vec<(usize, String)> = missing_entry
+                        .msgstr_plural
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, s)| !s.trim().is_empty())
+                        .map(|(i, s)| (i, s.clone()))
+                        .collect();
+                    if filled.is_empty() {
+                        continue;
+                    }
+                    has_non_empty_translations = true;
+
+                    if let Some(main_entry) = main_po.entries.iter_mut().find(|e| {
+                        e.msgid == missing_entry.msgid
+                            && e.msgctxt == missing_entry.msgctxt
+                            && e.msgid_plural == missing_entry.msgid_plural
+                    }) {
+                        if main_entry.msgstr_plural.len() < missing_entry.msgstr_plural.len() {
+                            main_entry
+                                .msgstr_plural
+                                .resize(missing_entry.msgstr_plural.len(), String::new());
+                        }
+                        for (i, s) in filled {
+                            main_entry.msgstr_plural[i] = s;
+                        }
+                        if missing_entry.flags.iter().any(|f| f == "fuzzy") {
+                            if !main_entry.flags.iter().any(|f| f == "fuzzy") {
+                                main_entry.flags.push("fuzzy".to_string());
+                            }
+                        } else {
+                            main_entry.flags.retain(|f| f != "fuzzy");
+                        }
+                        updated_count += 1;
+                    }
+                    continue;
+                }
+
                 // Check if this entry has a non-empty translation in messages-missing.po
                 if let Some(msgstr) = &missing_entry.msgstr {
                     if !msgstr.trim().is_empty() {
@@ -113,8 +390,18 @@ fn process_locale(base_path: &str, locale: &str, verbose: bool) -> Result<()> {
                         if let Some(main_entry) = main_po.entries.iter_mut().find(|e| {
                             e.msgid == missing_entry.msgid && e.msgctxt == missing_entry.msgctxt
                         }) {
-                            // Update the translation in the main PO file
+                            // Update the translation in the main PO file, and
+                            // clear fuzzy only once a human has confirmed it by
+                            // dropping the flag in messages-missing.po; a still
+                            // fuzzy entry (e.g. a fallback pre-fill) stays fuzzy
                             main_entry.msgstr = Some(msgstr.clone());
+                            if missing_entry.flags.iter().any(|f| f == "fuzzy") {
+                                if !main_entry.flags.iter().any(|f| f == "fuzzy") {
+                                    main_entry.flags.push("fuzzy".to_string());
+                                }
+                            } else {
+                                main_entry.flags.retain(|f| f != "fuzzy");
+                            }
                             updated_count += 1;
                         }
                     }
@@ -126,74 +413,175 @@ fn process_locale(base_path: &str, locale: &str, verbose: bool) -> Result<()> {
                 main_po.save(messages_path.as_os_str().to_str().unwrap());
 
                 if verbose {
-                    println!(
+                    log.push(format!(
                         "  🔄 {}: {} translations merged back from messages-missing.po",
                         locale, updated_count
-                    );
+                    ));
+                }
+
+                if compile {
+                    let mo_path = messages_path.with_extension("mo");
+                    write_mo_file(&main_po, &mo_path)
+                        .with_context(|| format!("Failed to write {}", mo_path.display()))?;
+                    if verbose {
+                        log.push(format!("  📦 {}: compiled {}", locale, mo_path.display()));
+                    }
                 }
 
                 // After merging, we can remove the messages-missing.po file
                 fs::remove_file(&messages_missing_path)?;
 
                 // Re-read the main PO file for the next steps since we just updated it
-                let main_po = pofile(messages_path.as_path()).map_err(|e| {
+                let mut main_po = pofile(messages_path.as_path()).map_err(|e| {
                     anyhow::anyhow!("Failed to read {}: {}", messages_path.display(), e)
                 })?;
+                sync_with_template(&mut main_po, &template_path, &messages_path, locale, verbose, log)?;
 
                 // Continue to extract current missing translations
-                extract_current_missing(
+                coverage = extract_current_missing(
                     &main_po,
-                    &messages_path,
                     &messages_missing_path,
                     locale,
                     verbose,
+                    fallback_chains,
+                    snapshot,
+                    include_fuzzy,
+                    log,
                 )?;
             } else {
                 // No non-empty translations found, proceed with normal extraction
-                extract_current_missing(
+                sync_with_template(&mut main_po, &template_path, &messages_path, locale, verbose, log)?;
+                coverage = extract_current_missing(
                     &main_po,
-                    &messages_path,
                     &messages_missing_path,
                     locale,
                     verbose,
+                    fallback_chains,
+                    snapshot,
+                    include_fuzzy,
+                    log,
                 )?;
             }
         } else {
             // If we can't read messages-missing.po, just proceed with normal extraction
-            let main_po = pofile(messages_path.as_path()).map_err(|e| {
+            let mut main_po = pofile(messages_path.as_path()).map_err(|e| {
                 anyhow::anyhow!("Failed to read {}: {}", messages_path.display(), e)
             })?;
-            extract_current_missing(
+            sync_with_template(&mut main_po, &template_path, &messages_path, locale, verbose, log)?;
+            coverage = extract_current_missing(
                 &main_po,
-                &messages_path,
                 &messages_missing_path,
                 locale,
                 verbose,
+                fallback_chains,
+                snapshot,
+                include_fuzzy,
+                log,
             )?;
         }
     } else {
         // No messages-missing.po exists, proceed with normal extraction
-        let main_po = pofile(messages_path.as_path())
+        let mut main_po = pofile(messages_path.as_path())
             .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", messages_path.display(), e))?;
-        extract_current_missing(
+        sync_with_template(&mut main_po, &template_path, &messages_path, locale, verbose, log)?;
+        coverage = extract_current_missing(
             &main_po,
-            &messages_path,
             &messages_missing_path,
             locale,
             verbose,
+            fallback_chains,
+            snapshot,
+            include_fuzzy,
+            log,
         )?;
     }
 
-    Ok(())
+    Ok(Some(coverage))
 }
 
+/// Walks an ordered ancestor-locale chain and returns the first non-empty
+/// translation found for `(msgid, msgctxt)`, mirroring how resource-registry
+/// resolvers fall back through a locale list.
+///
+/// Looks ancestors up in `snapshot`, a pre-read copy of every locale's
+/// catalog taken before parallel processing begins, rather than reading
+/// `messages.po` off disk again here — another locale's worker may be
+/// mid-save of that very file at the same moment.
+fn fallback_translation(
+    snapshot: &HashMap<String, POFile>,
+    chain: &[String],
+    msgid: &str,
+    msgctxt: &Option<String>,
+) -> Option<String> {
+    for ancestor in chain {
+        let Some(ancestor_po) = snapshot.get(ancestor) else {
+            continue;
+        };
+
+        let found = ancestor_po
+            .entries
+            .iter()
+            .find(|e| e.msgid == msgid && &e.msgctxt == msgctxt)
+            .and_then(|e| e.msgstr.as_ref())
+            .filter(|s| !s.trim().is_empty())
+            .cloned();
+
+        if found.is_some() {
+            return found;
+        }
+    }
+
+    None
+}
+
+/// Plural-aware sibling of [`fallback_translation`]: walks the same ancestor
+/// chain looking for a matching `(msgid, msgctxt, msgid_plural)` entry and
+/// returns its `msgstr_plural[index]`, so `--fallback` can pre-fill
+/// individual plural forms rather than only whole singular strings.
+fn fallback_plural_translation(
+    snapshot: &HashMap<String, POFile>,
+    chain: &[String],
+    msgid: &str,
+    msgctxt: &Option<String>,
+    msgid_plural: Option<&str>,
+    index: usize,
+) -> Option<String> {
+    for ancestor in chain {
+        let Some(ancestor_po) = snapshot.get(ancestor) else {
+            continue;
+        };
+
+        let found = ancestor_po
+            .entries
+            .iter()
+            .find(|e| {
+                e.msgid == msgid
+                    && &e.msgctxt == msgctxt
+                    && e.msgid_plural.as_deref() == msgid_plural
+            })
+            .and_then(|e| e.msgstr_plural.get(index))
+            .filter(|s| !s.trim().is_empty())
+            .cloned();
+
+        if found.is_some() {
+            return found;
+        }
+    }
+
+    None
+}
+
+#[allow(clippy::too_many_arguments)]
 fn extract_current_missing(
     main_po: &POFile,
-    messages_path: &PathBuf,
     messages_missing_path: &PathBuf,
     locale: &str,
     verbose: bool,
-) -> Result<()> {
+    fallback_chains: &HashMap<String, Vec<String>>,
+    snapshot: &HashMap<String, POFile>,
+    include_fuzzy: bool,
+    log: &mut Vec<String>,
+) -> Result<LocaleCoverage> {
     // Create options for new PO file
     let empty_opts = FileOptions {
         path_or_content: "".into(),
@@ -204,49 +592,365 @@ fn extract_current_missing(
     // Create new missing PO file
     let mut new_missing_po = POFile::new(empty_opts.clone());
 
-    // Copy header from main PO file if it exists
-    if let Some(header_entry) = main_po.entries.iter().find(|e| e.msgid.is_empty()) {
-        new_missing_po.entries.push(header_entry.clone());
-    }
+    // Carry the catalog's metadata (including Plural-Forms) over to
+    // messages-missing.po so a translator's editor still knows the right
+    // number of plural slots to fill in.
+    new_missing_po.metadata = main_po.metadata.clone();
+    new_missing_po.metadata_is_fuzzy = main_po.metadata_is_fuzzy;
+
+    let nplurals = header_nplurals(main_po);
 
     // Find and add entries with missing translations
+    let mut total = 0;
     let mut missing_count = 0;
+    let mut fuzzy_count = 0;
+    let mut missing_msgids = Vec::new();
     for entry in &main_po.entries {
-        // Skip the header entry (empty msgid)
-        if entry.msgid.is_empty() {
+        // Skip the header entry (empty msgid) and entries `sync_with_template`
+        // has marked obsolete — they're kept around as `#~` history, not
+        // live strings a translator still needs to fill in.
+        if entry.msgid.is_empty() || entry.obsolete {
             continue;
         }
+        total += 1;
+
+        let has_fuzzy_flag = entry.flags.iter().any(|f| f == "fuzzy");
+        if has_fuzzy_flag {
+            fuzzy_count += 1;
+        }
+        let is_fuzzy = include_fuzzy && has_fuzzy_flag;
 
-        // Check if translation is missing (None, empty, or only whitespace)
-        let is_missing = match &entry.msgstr {
+        if entry.msgid_plural.is_some() {
+            let missing_indices = missing_plural_indices(entry, nplurals);
+            if missing_indices.is_empty() && !is_fuzzy {
+                continue;
+            }
+
+            // Preserve forms the translator already filled in, try to
+            // pre-fill each still-missing form from the locale's fallback
+            // chain, and leave an empty slot for whatever's left so the
+            // translator sees the whole set of required plural forms at
+            // once. Fallback-filled forms are flagged fuzzy, same as the
+            // singular path, so a human still reviews them.
+            let mut entry = entry.clone();
+            if entry.msgstr_plural.len() < nplurals {
+                entry.msgstr_plural.resize(nplurals, String::new());
+            }
+            let chain = fallback_chains.get(locale);
+            let mut filled_from_fallback = false;
+            for i in missing_indices {
+                let fallback = chain.and_then(|chain| {
+                    fallback_plural_translation(
+                        snapshot,
+                        chain,
+                        &entry.msgid,
+                        &entry.msgctxt,
+                        entry.msgid_plural.as_deref(),
+                        i,
+                    )
+                });
+                if let Some(msgstr) = fallback {
+                    entry.msgstr_plural[i] = msgstr;
+                    filled_from_fallback = true;
+                }
+            }
+            if filled_from_fallback && !entry.flags.iter().any(|f| f == "fuzzy") {
+                entry.flags.push("fuzzy".to_string());
+            }
+            missing_msgids.push(entry.msgid.clone());
+            new_missing_po.entries.push(entry);
+            missing_count += 1;
+            continue;
+        }
+
+        // Check if translation is missing (None, empty, or only whitespace),
+        // or carries a fuzzy flag and is therefore not yet trustworthy
+        let is_empty = match &entry.msgstr {
             None => true,
             Some(s) => s.trim().is_empty(),
         };
 
-        if is_missing {
+        if !is_empty && !is_fuzzy {
+            continue;
+        }
+
+        if !is_empty {
+            // Fuzzy but already has text: quarantine as-is so the
+            // translator can correct it rather than retype it.
+            missing_msgids.push(entry.msgid.clone());
             new_missing_po.entries.push(entry.clone());
             missing_count += 1;
+            continue;
         }
+
+        // Try to pre-fill from the locale's fallback chain before giving up
+        // on the string entirely; fallback-filled entries are flagged fuzzy
+        // so a human still reviews them.
+        if let Some(chain) = fallback_chains.get(locale) {
+            if let Some(fallback_msgstr) =
+                fallback_translation(snapshot, chain, &entry.msgid, &entry.msgctxt)
+            {
+                let mut entry = entry.clone();
+                entry.msgstr = Some(fallback_msgstr);
+                if !entry.flags.iter().any(|f| f == "fuzzy") {
+                    entry.flags.push("fuzzy".to_string());
+                }
+                missing_msgids.push(entry.msgid.clone());
+                new_missing_po.entries.push(entry);
+                missing_count += 1;
+                continue;
+            }
+        }
+
+        missing_msgids.push(entry.msgid.clone());
+        new_missing_po.entries.push(entry.clone());
+        missing_count += 1;
     }
 
     // Only create messages-missing.po if there are actual missing translations
     if missing_count > 0 {
         new_missing_po.save(messages_missing_path.as_os_str().to_str().unwrap());
         if verbose {
-            println!(
+            log.push(format!(
                 "  ✅ {}: {} missing translations extracted",
                 locale, missing_count
-            );
+            ));
         }
     } else {
         // Remove messages-missing.po if it exists and there are no missing translations
         if messages_missing_path.exists() {
-            fs::remove_file(&messages_missing_path)?;
+            fs::remove_file(messages_missing_path)?;
+        }
+        if verbose {
+            log.push(format!("  ✅ {}: no missing translations", locale));
         }
+    }
+
+    let translated = total - missing_count;
+    Ok(LocaleCoverage {
+        locale: locale.to_string(),
+        total,
+        translated,
+        missing: missing_count,
+        fuzzy: fuzzy_count,
+        percent_coverage: if total == 0 {
+            100.0
+        } else {
+            (translated as f64 / total as f64) * 100.0
+        },
+        missing_msgids,
+    })
+}
+
+/// Reconciles `main_po` against the `.pot` template at `template_path`,
+/// mirroring the `msginit`/`msgmerge` workflow: entries the template gained
+/// are inserted with an empty translation, entries the template dropped are
+/// kept but marked obsolete (`#~`) rather than deleted, and source-location
+/// (`#:`) / extracted (`#.`) comments are refreshed from the template onto
+/// matched entries. A no-op (and no resave) if `template_path` doesn't exist.
+fn sync_with_template(
+    main_po: &mut POFile,
+    template_path: &Path,
+    messages_path: &Path,
+    locale: &str,
+    verbose: bool,
+    log: &mut Vec<String>,
+) -> Result<()> {
+    if !template_path.exists() {
+        return Ok(());
+    }
+
+    let template_po = pofile(template_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read template {}: {}", template_path.display(), e))?;
+
+    let mut added = 0;
+    let mut obsoleted = 0;
+
+    for template_entry in &template_po.entries {
+        if template_entry.msgid.is_empty() {
+            continue;
+        }
+
+        if let Some(main_entry) = main_po.entries.iter_mut().find(|e| {
+            e.msgid == template_entry.msgid && e.msgctxt == template_entry.msgctxt
+        }) {
+            main_entry.occurrences = template_entry.occurrences.clone();
+            main_entry.comment = template_entry.comment.clone();
+            main_entry.obsolete = false;
+        } else {
+            let mut new_entry = template_entry.clone();
+            new_entry.msgstr = Some(String::new());
+            main_po.entries.push(new_entry);
+            added += 1;
+        }
+    }
+
+    for main_entry in main_po.entries.iter_mut() {
+        if main_entry.msgid.is_empty() || main_entry.obsolete {
+            continue;
+        }
+        let still_in_template = template_po
+            .entries
+            .iter()
+            .any(|e| e.msgid == main_entry.msgid && e.msgctxt == main_entry.msgctxt);
+        if !still_in_template {
+            main_entry.obsolete = true;
+            obsoleted += 1;
+        }
+    }
+
+    if added > 0 || obsoleted > 0 {
+        main_po.save(messages_path.as_os_str().to_str().unwrap());
         if verbose {
-            println!("  ✅ {}: no missing translations", locale);
+            log.push(format!(
+                "  📐 {}: synced with template ({} added, {} marked obsolete)",
+                locale, added, obsoleted
+            ));
         }
     }
 
     Ok(())
 }
+
+/// Writes `po` out as a binary GNU MO catalog at `mo_path`, delegating to
+/// rspolib's own `POFile::save_as_mofile` rather than hand-rolling the
+/// binary format. The library already excludes obsolete and fuzzy entries,
+/// and (via `POEntry::translated`) partially-translated plural entries,
+/// matching `msgfmt`'s default behaviour.
+fn write_mo_file(po: &POFile, mo_path: &Path) -> Result<()> {
+    let path = mo_path
+        .to_str()
+        .with_context(|| format!("Non-UTF-8 path '{}'", mo_path.display()))?;
+    po.save_as_mofile(path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(msgid: &str, msgstr: &str) -> POEntry {
+        POEntry {
+            msgid: msgid.to_string(),
+            msgstr: Some(msgstr.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn plural_entry(msgid: &str, msgid_plural: &str, forms: &[&str]) -> POEntry {
+        POEntry {
+            msgid: msgid.to_string(),
+            msgid_plural: Some(msgid_plural.to_string()),
+            msgstr_plural: forms.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn po_with_entries(entries: Vec<POEntry>) -> POFile {
+        let mut po = POFile::new(FileOptions::default());
+        po.entries = entries;
+        po
+    }
+
+    #[test]
+    fn missing_plural_indices_flags_empty_and_whitespace_forms() {
+        let entry = plural_entry("cat", "cats", &["one cat", "  ", ""]);
+        assert_eq!(missing_plural_indices(&entry, 3), vec![1, 2]);
+    }
+
+    #[test]
+    fn missing_plural_indices_flags_forms_shorter_than_nplurals() {
+        let entry = plural_entry("cat", "cats", &["one cat"]);
+        assert_eq!(missing_plural_indices(&entry, 3), vec![1, 2]);
+    }
+
+    #[test]
+    fn header_nplurals_reads_plural_forms_from_metadata() {
+        let mut po = POFile::new(FileOptions::default());
+        po.metadata.insert(
+            "Plural-Forms".to_string(),
+            "nplurals=3; plural=(n==1 ? 0 : n==2 ? 1 : 2);".to_string(),
+        );
+        assert_eq!(header_nplurals(&po), 3);
+    }
+
+    #[test]
+    fn header_nplurals_defaults_to_two_without_metadata() {
+        let po = POFile::new(FileOptions::default());
+        assert_eq!(header_nplurals(&po), 2);
+    }
+
+    #[test]
+    fn fallback_translation_walks_ancestor_chain_in_order() {
+        let en = po_with_entries(vec![entry("hello", "Hello")]);
+        let snapshot: HashMap<String, POFile> = [("en".to_string(), en)].into_iter().collect();
+
+        let chain = vec!["fr".to_string(), "en".to_string()];
+        let found = fallback_translation(&snapshot, &chain, "hello", &None);
+        assert_eq!(found, Some("Hello".to_string()));
+    }
+
+    #[test]
+    fn fallback_translation_skips_blank_ancestor_translations() {
+        let fr = po_with_entries(vec![entry("hello", "   ")]);
+        let en = po_with_entries(vec![entry("hello", "Hello")]);
+        let snapshot: HashMap<String, POFile> =
+            [("fr".to_string(), fr), ("en".to_string(), en)]
+                .into_iter()
+                .collect();
+
+        let chain = vec!["fr".to_string(), "en".to_string()];
+        let found = fallback_translation(&snapshot, &chain, "hello", &None);
+        assert_eq!(found, Some("Hello".to_string()));
+    }
+
+    #[test]
+    fn fallback_plural_translation_reads_requested_index() {
+        let en = po_with_entries(vec![plural_entry("cat", "cats", &["one cat", "N cats"])]);
+        let snapshot: HashMap<String, POFile> = [("en".to_string(), en)].into_iter().collect();
+
+        let chain = vec!["en".to_string()];
+        let found =
+            fallback_plural_translation(&snapshot, &chain, "cat", &None, Some("cats"), 1);
+        assert_eq!(found, Some("N cats".to_string()));
+    }
+
+    #[test]
+    fn fallback_plural_translation_none_when_form_still_empty() {
+        let en = po_with_entries(vec![plural_entry("cat", "cats", &["one cat", ""])]);
+        let snapshot: HashMap<String, POFile> = [("en".to_string(), en)].into_iter().collect();
+
+        let chain = vec!["en".to_string()];
+        let found =
+            fallback_plural_translation(&snapshot, &chain, "cat", &None, Some("cats"), 1);
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn write_mo_file_excludes_fuzzy_and_partial_plural_entries() {
+        let mut fuzzy = entry("bye", "Au revoir");
+        fuzzy.flags.push("fuzzy".to_string());
+
+        let po = po_with_entries(vec![
+            entry("hello", "Bonjour"),
+            fuzzy,
+            entry("untranslated", ""),
+            plural_entry("dog", "dogs", &["un chien", ""]),
+            plural_entry("cat", "cats", &["un chat", "des chats"]),
+        ]);
+
+        let mo_path = std::env::temp_dir().join("po_missing_write_mo_file_test.mo");
+        write_mo_file(&po, &mo_path).unwrap();
+
+        let compiled = rspolib::mofile(mo_path.as_path()).unwrap();
+        let msgids: Vec<&str> = compiled.entries.iter().map(|e| e.msgid.as_str()).collect();
+
+        fs::remove_file(&mo_path).ok();
+
+        assert!(msgids.contains(&"hello"));
+        assert!(msgids.contains(&"cat"));
+        assert!(!msgids.contains(&"bye"));
+        assert!(!msgids.contains(&"untranslated"));
+        assert!(!msgids.contains(&"dog"));
+    }
+}